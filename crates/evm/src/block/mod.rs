@@ -0,0 +1,119 @@
+//! Block execution types.
+
+pub mod system_calls;
+
+pub use system_calls::SystemCaller;
+
+use alloc::{boxed::Box, string::String};
+use alloy_primitives::B256;
+use core::fmt;
+
+/// Re-exported here so the `system_calls` helpers can refer to it as `crate::block::ExecutionResult`.
+pub use revm::context_interface::result::ExecutionResult;
+
+/// Errors that can occur during block execution.
+#[derive(Debug)]
+pub enum BlockExecutionError {
+    /// Error validating a block, including errors encountered while applying the pre- and
+    /// post-block system calls.
+    Validation(BlockValidationError),
+}
+
+impl fmt::Display for BlockExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Validation(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl core::error::Error for BlockExecutionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Validation(err) => Some(err),
+        }
+    }
+}
+
+impl From<BlockValidationError> for BlockExecutionError {
+    fn from(err: BlockValidationError) -> Self {
+        Self::Validation(err)
+    }
+}
+
+/// Errors that can occur when validating a block, including errors encountered while applying
+/// the pre- and post-block system calls.
+#[derive(Debug)]
+pub enum BlockValidationError {
+    /// The parent beacon block root is missing for a post-Cancun block.
+    MissingParentBeaconBlockRoot,
+    /// The parent beacon block root is not zero for the genesis block.
+    CancunGenesisParentBeaconBlockRootNotZero {
+        /// The parent beacon block root.
+        parent_beacon_block_root: B256,
+    },
+    /// Error when applying the [EIP-4788] beacon root contract call.
+    ///
+    /// [EIP-4788]: https://eips.ethereum.org/EIPS/eip-4788
+    BeaconRootContractCall {
+        /// The parent beacon block root.
+        parent_beacon_block_root: Box<B256>,
+        /// The error message.
+        message: String,
+    },
+    /// Error when applying the [EIP-2935] blockhashes contract call.
+    ///
+    /// [EIP-2935]: https://eips.ethereum.org/EIPS/eip-2935
+    BlockHashContractCall {
+        /// The error message.
+        message: String,
+    },
+    /// Error when applying the [EIP-7002] withdrawal requests contract call.
+    ///
+    /// [EIP-7002]: https://eips.ethereum.org/EIPS/eip-7002
+    WithdrawalRequestsContractCall {
+        /// The error message.
+        message: String,
+    },
+    /// Error when applying the [EIP-7251] consolidation requests contract call.
+    ///
+    /// [EIP-7251]: https://eips.ethereum.org/EIPS/eip-7251
+    ConsolidationRequestsContractCall {
+        /// The error message.
+        message: String,
+    },
+}
+
+impl fmt::Display for BlockValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingParentBeaconBlockRoot => {
+                write!(f, "missing parent beacon block root")
+            }
+            Self::CancunGenesisParentBeaconBlockRootNotZero { parent_beacon_block_root } => {
+                write!(
+                    f,
+                    "parent beacon block root is not zero at genesis: {parent_beacon_block_root}"
+                )
+            }
+            Self::BeaconRootContractCall { parent_beacon_block_root, message } => {
+                write!(
+                    f,
+                    "failed to apply beacon root contract call (parent beacon block root \
+                     {parent_beacon_block_root}): {message}"
+                )
+            }
+            Self::BlockHashContractCall { message } => {
+                write!(f, "failed to apply blockhashes contract call: {message}")
+            }
+            Self::WithdrawalRequestsContractCall { message } => {
+                write!(f, "failed to apply withdrawal requests contract call: {message}")
+            }
+            Self::ConsolidationRequestsContractCall { message } => {
+                write!(f, "failed to apply consolidation requests contract call: {message}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for BlockValidationError {}
@@ -0,0 +1,117 @@
+//! [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002) system call implementation.
+
+use super::{success_output, transact_system_call};
+use crate::{
+    block::{BlockExecutionError, BlockValidationError},
+    Evm,
+};
+use alloc::{format, vec::Vec};
+use alloy_eips::eip7002::{WithdrawalRequest, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS};
+use alloy_primitives::{Address, Bytes, FixedBytes};
+use revm::context_interface::result::ResultAndState;
+
+/// The length in bytes of a single ABI-packed withdrawal request record: a 20-byte source
+/// address, a 48-byte validator public key and an 8-byte amount.
+const WITHDRAWAL_REQUEST_SIZE: usize = 20 + 48 + 8;
+
+/// Transacts the post-block call to the [EIP-7002] withdrawal-requests contract, using the
+/// given EVM.
+///
+/// Activation gating (Prague active) is the responsibility of the caller; see
+/// [`SystemCaller::collect_requests`](super::SystemCaller::collect_requests).
+///
+/// Note: this does not commit the state changes to the database, it only transacts the call.
+///
+/// Returns the result of the call alongside the withdrawal requests decoded from its output.
+///
+/// [EIP-7002]: https://eips.ethereum.org/EIPS/eip-7002
+#[inline]
+pub(crate) fn transact_withdrawal_requests_contract_call<Halt>(
+    evm: &mut impl Evm<HaltReason = Halt>,
+) -> Result<(ResultAndState<Halt>, Vec<WithdrawalRequest>), BlockExecutionError> {
+    let res =
+        transact_system_call(evm, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS, Bytes::new()).map_err(
+            |message| BlockValidationError::WithdrawalRequestsContractCall { message },
+        )?;
+
+    let requests = parse_withdrawal_requests(success_output(&res))?;
+
+    Ok((res, requests))
+}
+
+/// Decodes the ABI-packed output of the [EIP-7002] withdrawal-requests contract into a list of
+/// [`WithdrawalRequest`]s.
+///
+/// [EIP-7002]: https://eips.ethereum.org/EIPS/eip-7002
+fn parse_withdrawal_requests(data: &[u8]) -> Result<Vec<WithdrawalRequest>, BlockExecutionError> {
+    if data.len() % WITHDRAWAL_REQUEST_SIZE != 0 {
+        return Err(BlockValidationError::WithdrawalRequestsContractCall {
+            message: format!(
+                "withdrawal requests output length {} is not a multiple of {WITHDRAWAL_REQUEST_SIZE}",
+                data.len()
+            ),
+        }
+        .into());
+    }
+
+    Ok(data
+        .chunks_exact(WITHDRAWAL_REQUEST_SIZE)
+        .map(|chunk| WithdrawalRequest {
+            source_address: Address::from_slice(&chunk[..20]),
+            validator_pubkey: FixedBytes::<48>::from_slice(&chunk[20..68]),
+            amount: u64::from_be_bytes(chunk[68..76].try_into().unwrap()),
+        })
+        .collect())
+}
+
+/// Encodes a list of [`WithdrawalRequest`]s back into the ABI-packed layout used by the
+/// [EIP-7685] requests list, i.e. the inverse of [`parse_withdrawal_requests`].
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub(crate) fn encode_withdrawal_requests(requests: &[WithdrawalRequest]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(requests.len() * WITHDRAWAL_REQUEST_SIZE);
+    for request in requests {
+        out.extend_from_slice(request.source_address.as_slice());
+        out.extend_from_slice(request.validator_pubkey.as_slice());
+        out.extend_from_slice(&request.amount.to_be_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_parse_withdrawal_requests_round_trip() {
+        let requests = alloc::vec![
+            WithdrawalRequest {
+                source_address: Address::repeat_byte(0x11),
+                validator_pubkey: FixedBytes::<48>::repeat_byte(0x22),
+                amount: 0,
+            },
+            WithdrawalRequest {
+                source_address: Address::repeat_byte(0x33),
+                validator_pubkey: FixedBytes::<48>::repeat_byte(0x44),
+                amount: u64::MAX,
+            },
+        ];
+
+        let encoded = encode_withdrawal_requests(&requests);
+        let decoded = parse_withdrawal_requests(&encoded).unwrap();
+
+        assert_eq!(decoded, requests);
+    }
+
+    #[test]
+    fn parse_withdrawal_requests_rejects_truncated_input() {
+        let err = parse_withdrawal_requests(&[0u8; WITHDRAWAL_REQUEST_SIZE + 1]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            BlockExecutionError::Validation(BlockValidationError::WithdrawalRequestsContractCall {
+                ..
+            })
+        ));
+    }
+}
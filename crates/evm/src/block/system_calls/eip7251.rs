@@ -0,0 +1,117 @@
+//! [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251) system call implementation.
+
+use super::{success_output, transact_system_call};
+use crate::{
+    block::{BlockExecutionError, BlockValidationError},
+    Evm,
+};
+use alloc::{format, vec::Vec};
+use alloy_eips::eip7251::{ConsolidationRequest, CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS};
+use alloy_primitives::{Address, Bytes, FixedBytes};
+use revm::context_interface::result::ResultAndState;
+
+/// The length in bytes of a single ABI-packed consolidation request record: a 20-byte source
+/// address, a 48-byte source public key and a 48-byte target public key.
+const CONSOLIDATION_REQUEST_SIZE: usize = 20 + 48 + 48;
+
+/// Transacts the post-block call to the [EIP-7251] consolidation-requests contract, using the
+/// given EVM.
+///
+/// Activation gating (Prague active) is the responsibility of the caller; see
+/// [`SystemCaller::collect_requests`](super::SystemCaller::collect_requests).
+///
+/// Note: this does not commit the state changes to the database, it only transacts the call.
+///
+/// Returns the result of the call alongside the consolidation requests decoded from its output.
+///
+/// [EIP-7251]: https://eips.ethereum.org/EIPS/eip-7251
+#[inline]
+pub(crate) fn transact_consolidation_requests_contract_call<Halt>(
+    evm: &mut impl Evm<HaltReason = Halt>,
+) -> Result<(ResultAndState<Halt>, Vec<ConsolidationRequest>), BlockExecutionError> {
+    let res = transact_system_call(evm, CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS, Bytes::new())
+        .map_err(|message| BlockValidationError::ConsolidationRequestsContractCall { message })?;
+
+    let requests = parse_consolidation_requests(success_output(&res))?;
+
+    Ok((res, requests))
+}
+
+/// Decodes the ABI-packed output of the [EIP-7251] consolidation-requests contract into a list
+/// of [`ConsolidationRequest`]s.
+///
+/// [EIP-7251]: https://eips.ethereum.org/EIPS/eip-7251
+fn parse_consolidation_requests(
+    data: &[u8],
+) -> Result<Vec<ConsolidationRequest>, BlockExecutionError> {
+    if data.len() % CONSOLIDATION_REQUEST_SIZE != 0 {
+        return Err(BlockValidationError::ConsolidationRequestsContractCall {
+            message: format!(
+                "consolidation requests output length {} is not a multiple of {CONSOLIDATION_REQUEST_SIZE}",
+                data.len()
+            ),
+        }
+        .into());
+    }
+
+    Ok(data
+        .chunks_exact(CONSOLIDATION_REQUEST_SIZE)
+        .map(|chunk| ConsolidationRequest {
+            source_address: Address::from_slice(&chunk[..20]),
+            source_pubkey: FixedBytes::<48>::from_slice(&chunk[20..68]),
+            target_pubkey: FixedBytes::<48>::from_slice(&chunk[68..116]),
+        })
+        .collect())
+}
+
+/// Encodes a list of [`ConsolidationRequest`]s back into the ABI-packed layout used by the
+/// [EIP-7685] requests list, i.e. the inverse of [`parse_consolidation_requests`].
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub(crate) fn encode_consolidation_requests(requests: &[ConsolidationRequest]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(requests.len() * CONSOLIDATION_REQUEST_SIZE);
+    for request in requests {
+        out.extend_from_slice(request.source_address.as_slice());
+        out.extend_from_slice(request.source_pubkey.as_slice());
+        out.extend_from_slice(request.target_pubkey.as_slice());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_parse_consolidation_requests_round_trip() {
+        let requests = alloc::vec![
+            ConsolidationRequest {
+                source_address: Address::repeat_byte(0x11),
+                source_pubkey: FixedBytes::<48>::repeat_byte(0x22),
+                target_pubkey: FixedBytes::<48>::repeat_byte(0x33),
+            },
+            ConsolidationRequest {
+                source_address: Address::repeat_byte(0x44),
+                source_pubkey: FixedBytes::<48>::repeat_byte(0x55),
+                target_pubkey: FixedBytes::<48>::repeat_byte(0x66),
+            },
+        ];
+
+        let encoded = encode_consolidation_requests(&requests);
+        let decoded = parse_consolidation_requests(&encoded).unwrap();
+
+        assert_eq!(decoded, requests);
+    }
+
+    #[test]
+    fn parse_consolidation_requests_rejects_truncated_input() {
+        let err = parse_consolidation_requests(&[0u8; CONSOLIDATION_REQUEST_SIZE + 1]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            BlockExecutionError::Validation(
+                BlockValidationError::ConsolidationRequestsContractCall { .. }
+            )
+        ));
+    }
+}
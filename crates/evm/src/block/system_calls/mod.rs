@@ -0,0 +1,220 @@
+//! System contract calls.
+//!
+//! Chains the pre- and post-block system contract calls behind a single [`SystemCaller`], so
+//! callers no longer need to thread the chain spec and [`Evm`] through a handful of free
+//! functions, and the Cancun/Prague/genesis activation gating lives in one place instead of
+//! being re-implemented by each system call.
+//!
+//! [`SystemCaller`] only transacts the calls, it does not commit their state changes to the
+//! database: each method returns the [`ResultAndState`] of every call it made, in the order they
+//! were made, so the caller can commit them to the database itself.
+
+mod eip2935;
+mod eip4788;
+mod eip7002;
+mod eip7251;
+
+use crate::{
+    block::{BlockExecutionError, BlockValidationError, ExecutionResult},
+    Evm,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_eips::eip7685::Requests;
+use alloy_hardforks::EthereumHardforks;
+use alloy_primitives::{Address, Bytes, B256};
+use eip2935::transact_blockhashes_contract_call;
+use eip4788::transact_beacon_root_contract_call;
+use eip7002::{encode_withdrawal_requests, transact_withdrawal_requests_contract_call};
+use eip7251::{encode_consolidation_requests, transact_consolidation_requests_contract_call};
+use revm::context_interface::result::ResultAndState;
+
+/// The [EIP-7685] request type byte for [EIP-7002] withdrawal requests.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+/// [EIP-7002]: https://eips.ethereum.org/EIPS/eip-7002
+const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+
+/// The [EIP-7685] request type byte for [EIP-7251] consolidation requests.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+/// [EIP-7251]: https://eips.ethereum.org/EIPS/eip-7251
+const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
+
+/// Chains the pre- and post-block system contract calls for a single block.
+///
+/// Holds a mutable borrow of the [`Evm`] used to transact the calls and owns the chain spec used
+/// to gate their activation (Cancun, Prague, genesis), so callers can drive
+/// [`Self::apply_pre_execution_changes`] and [`Self::apply_post_execution_changes`] without
+/// re-threading either through every call site, and the individual system calls don't each
+/// re-implement the same gating checks.
+#[allow(missing_debug_implementations)]
+pub struct SystemCaller<'evm, Spec, EvmT> {
+    spec: Spec,
+    evm: &'evm mut EvmT,
+}
+
+impl<'evm, Spec, EvmT> SystemCaller<'evm, Spec, EvmT> {
+    /// Creates a new [`SystemCaller`] for the given chain spec and [`Evm`].
+    pub fn new(spec: Spec, evm: &'evm mut EvmT) -> Self {
+        Self { spec, evm }
+    }
+}
+
+impl<'evm, Spec, EvmT> SystemCaller<'evm, Spec, EvmT>
+where
+    Spec: EthereumHardforks,
+    EvmT: Evm,
+{
+    /// Applies the [EIP-4788] beacon root and [EIP-2935] blockhashes pre-block system calls, in
+    /// that order, gating each on its activation condition and on the block not being genesis.
+    ///
+    /// Note: this does not commit the state changes to the database itself, it only transacts
+    /// the calls. The caller is responsible for committing the returned [`ResultAndState`]s, in
+    /// order, to the database.
+    ///
+    /// [EIP-4788]: https://eips.ethereum.org/EIPS/eip-4788
+    /// [EIP-2935]: https://eips.ethereum.org/EIPS/eip-2935
+    pub fn apply_pre_execution_changes(
+        &mut self,
+        parent_beacon_block_root: Option<B256>,
+        parent_block_hash: B256,
+    ) -> Result<Vec<ResultAndState<EvmT::HaltReason>>, BlockExecutionError> {
+        let mut results = Vec::new();
+
+        let timestamp = self.evm.block().timestamp;
+        let is_genesis = self.evm.block().number == 0;
+
+        if self.spec.is_cancun_active_at_timestamp(timestamp) {
+            let parent_beacon_block_root = parent_beacon_block_root
+                .ok_or(BlockValidationError::MissingParentBeaconBlockRoot)?;
+
+            // As per EIP-4788, the parent beacon block root must be zero and no system
+            // transaction may occur at genesis.
+            if is_genesis {
+                if !parent_beacon_block_root.is_zero() {
+                    return Err(BlockValidationError::CancunGenesisParentBeaconBlockRootNotZero {
+                        parent_beacon_block_root,
+                    }
+                    .into());
+                }
+            } else {
+                results.push(transact_beacon_root_contract_call(
+                    parent_beacon_block_root,
+                    self.evm,
+                )?);
+            }
+        }
+
+        // As per EIP-2935, no system transaction may occur at genesis.
+        if self.spec.is_prague_active_at_timestamp(timestamp) && !is_genesis {
+            results.push(transact_blockhashes_contract_call(parent_block_hash, self.evm)?);
+        }
+
+        Ok(results)
+    }
+
+    /// Applies the post-block [EIP-7685] request system calls and returns the resulting
+    /// general-purpose [`Requests`] alongside the [`ResultAndState`]s of the calls that produced
+    /// them.
+    ///
+    /// Note: this does not commit the state changes to the database itself; see
+    /// [`Self::apply_pre_execution_changes`].
+    ///
+    /// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+    pub fn apply_post_execution_changes(
+        &mut self,
+    ) -> Result<(Requests, Vec<ResultAndState<EvmT::HaltReason>>), BlockExecutionError> {
+        self.collect_requests()
+    }
+
+    /// Runs the [EIP-7002] withdrawal and [EIP-7251] consolidation post-block request contract
+    /// calls, in that canonical order, and concatenates their decoded outputs into a single
+    /// [EIP-7685] [`Requests`] list. A no-op before Prague activates.
+    ///
+    /// Note: this does not commit the state changes to the database itself; see
+    /// [`Self::apply_pre_execution_changes`].
+    ///
+    /// [EIP-7002]: https://eips.ethereum.org/EIPS/eip-7002
+    /// [EIP-7251]: https://eips.ethereum.org/EIPS/eip-7251
+    /// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+    pub fn collect_requests(
+        &mut self,
+    ) -> Result<(Requests, Vec<ResultAndState<EvmT::HaltReason>>), BlockExecutionError> {
+        let mut requests = Requests::default();
+        let mut results = Vec::new();
+
+        if !self.spec.is_prague_active_at_timestamp(self.evm.block().timestamp) {
+            return Ok((requests, results));
+        }
+
+        let (res, withdrawal_requests) = transact_withdrawal_requests_contract_call(self.evm)?;
+        requests.push_request(request_bytes(
+            WITHDRAWAL_REQUEST_TYPE,
+            encode_withdrawal_requests(&withdrawal_requests),
+        ));
+        results.push(res);
+
+        let (res, consolidation_requests) = transact_consolidation_requests_contract_call(self.evm)?;
+        requests.push_request(request_bytes(
+            CONSOLIDATION_REQUEST_TYPE,
+            encode_consolidation_requests(&consolidation_requests),
+        ));
+        results.push(res);
+
+        Ok((requests, results))
+    }
+}
+
+/// Transacts a system call from the canonical system address to `target`, using the given
+/// calldata.
+///
+/// This is the shared entry point for every system contract call: it issues the call and maps a
+/// revert or halt into a `String` describing the failure, leaving the caller to wrap that
+/// message in its own [`BlockValidationError`] variant.
+///
+/// Note: this does not commit the state changes to the database, it only transacts the call.
+fn transact_system_call<Halt>(
+    evm: &mut impl Evm<HaltReason = Halt>,
+    target: Address,
+    calldata: Bytes,
+) -> Result<ResultAndState<Halt>, String> {
+    let res = evm
+        .transact_system_call(alloy_eips::eip4788::SYSTEM_ADDRESS, target, calldata)
+        .map_err(|e| e.to_string())?;
+
+    match &res.result {
+        ExecutionResult::Success { .. } => Ok(res),
+        ExecutionResult::Revert { output, .. } => {
+            Err(format!("call to {target} reverted: {output}"))
+        }
+        ExecutionResult::Halt { .. } => Err(format!("call to {target} halted")),
+    }
+}
+
+/// Returns the output bytes of a [`ResultAndState`] produced by [`transact_system_call`].
+///
+/// [`transact_system_call`] only ever returns `Ok` for a successful call, so the non-success
+/// arms are unreachable here.
+fn success_output<Halt>(res: &ResultAndState<Halt>) -> &Bytes {
+    match &res.result {
+        ExecutionResult::Success { output, .. } => output.data(),
+        ExecutionResult::Revert { .. } | ExecutionResult::Halt { .. } => {
+            unreachable!("transact_system_call only returns Ok on a successful call")
+        }
+    }
+}
+
+/// Builds an [EIP-7685] request entry by prefixing the encoded request body with its request
+/// type byte.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+fn request_bytes(request_type: u8, body: Vec<u8>) -> Bytes {
+    let mut request = Vec::with_capacity(1 + body.len());
+    request.push(request_type);
+    request.extend_from_slice(&body);
+    request.into()
+}